@@ -427,6 +427,7 @@ async fn inner_main(cli: Cli) -> anyhow::Result<()> {
 				bitcoind_pass: config.bitcoind_pass,
 				esplora: config.esplora_address,
 				vtxo_refresh_expiry_threshold: config.vtxo_refresh_expiry_threshold,
+				htlc_send_exit_expiry_threshold: config.htlc_send_exit_expiry_threshold,
 				fallback_fee_rate: config.fallback_fee_rate,
 			})
 		},
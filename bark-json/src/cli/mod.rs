@@ -152,6 +152,9 @@ pub struct Config {
 	pub esplora: Option<String>,
 	/// How many blocks before VTXO expiration before preemptively refreshing them
 	pub vtxo_refresh_expiry_threshold: BlockHeight,
+	/// How many blocks before expiration HTLC-send VTXOs of failed payments are exited,
+	/// falls back to `vtxo_refresh_expiry_threshold` if not set
+	pub htlc_send_exit_expiry_threshold: Option<BlockHeight>,
 	#[serde(rename = "fallback_fee_rate_kvb", with = "serde_utils::fee_rate_sats_per_kvb")]
 	#[cfg_attr(feature = "utoipa", schema(value_type = u64, nullable = true))]
 	pub fallback_fee_rate: Option<FeeRate>,
@@ -118,6 +118,17 @@ pub struct Config {
 	/// Default value: 18
 	pub htlc_recv_claim_delta: BlockDelta,
 
+	/// The number of blocks before expiration at which HTLC-send VTXOs that
+	/// could not be revoked are exited.
+	///
+	/// Unlike `vtxo_refresh_expiry_threshold`, which decides when healthy
+	/// VTXOs are refreshed, this only applies to HTLCs of failed payments.
+	/// Such HTLCs can only be claimed back unilaterally after the server's
+	/// `htlc_send_expiry_delta`, so a larger value leaves more room to exit.
+	///
+	/// Default value: unset, falls back to `vtxo_refresh_expiry_threshold`
+	pub htlc_send_exit_expiry_threshold: Option<BlockHeight>,
+
 	/// A fallback fee rate to use in sat/kWu when we fail to retrieve a fee rate from the
 	/// configured bitcoind/esplora connection.
 	///
@@ -146,6 +157,7 @@ impl Config {
 			vtxo_refresh_expiry_threshold: 144,
 			vtxo_exit_margin: 12,
 			htlc_recv_claim_delta: 18,
+			htlc_send_exit_expiry_threshold: None,
 			fallback_fee_rate: None,
 			round_tx_required_confirmations: 6,
 		};
@@ -171,5 +183,39 @@ impl Config {
 			.build().context("error building config")?
 			.try_deserialize::<Config>().context("error parsing config")?)
 	}
+
+	/// Whether HTLC-send VTXOs that could not be revoked and expire at
+	/// `expiry_height` should be exited at block height `tip`.
+	///
+	/// Uses [Config::htlc_send_exit_expiry_threshold], falling back to
+	/// [Config::vtxo_refresh_expiry_threshold] if it is not set.
+	pub fn htlc_send_needs_exit(&self, tip: BlockHeight, expiry_height: BlockHeight) -> bool {
+		let threshold = self.htlc_send_exit_expiry_threshold
+			.unwrap_or(self.vtxo_refresh_expiry_threshold);
+		tip > expiry_height.saturating_sub(threshold)
+	}
 }
 
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn htlc_send_needs_exit() {
+		let mut config = Config::network_default(Network::Bitcoin);
+		assert_eq!(config.htlc_send_exit_expiry_threshold, None);
+		assert_eq!(config.vtxo_refresh_expiry_threshold, 144);
+
+		// falls back to the refresh threshold
+		assert!(!config.htlc_send_needs_exit(1_000 - 144, 1_000));
+		assert!(config.htlc_send_needs_exit(1_000 - 143, 1_000));
+
+		config.htlc_send_exit_expiry_threshold = Some(500);
+		assert!(!config.htlc_send_needs_exit(1_000 - 500, 1_000));
+		assert!(config.htlc_send_needs_exit(1_000 - 499, 1_000));
+		assert!(config.htlc_send_needs_exit(1_000 - 143, 1_000));
+
+		// expiry below the threshold
+		assert!(config.htlc_send_needs_exit(1, 400));
+	}
+}
@@ -338,7 +338,7 @@ use crate::persist::{BarkPersister, RoundStateId};
 use crate::persist::models::{LightningReceive, LightningSend, PendingBoard};
 use crate::round::{RoundParticipation, RoundStatus};
 use crate::subsystem::{ArkoorMovement, BarkSubsystem, BoardMovement, RoundMovement, SubsystemId};
-use crate::vtxo::selection::{FilterVtxos, VtxoFilter, RefreshStrategy};
+use crate::vtxo::selection::{select_to_cover, FilterVtxos, VtxoFilter, RefreshStrategy};
 use crate::vtxo::state::{VtxoState, VtxoStateKind, UNSPENT_STATES};
 
 const ARK_PURPOSE_INDEX: u32 = 350;
@@ -1800,34 +1800,7 @@ impl Wallet {
 		amount: Amount,
 		expiry_threshold: Option<BlockHeight>,
 	) -> anyhow::Result<Vec<Vtxo>> {
-		let inputs = self.spendable_vtxos()?;
-
-		// Iterate over all rows until the required amount is reached
-		let mut result = Vec::new();
-		let mut total_amount = bitcoin::Amount::ZERO;
-		for input in inputs {
-			// Check if vtxo is soon-to-expire for arkoor payments
-			if let Some(threshold) = expiry_threshold {
-				if input.expiry_height() < threshold {
-					warn!("VTXO {} is expiring soon (expires at {}, threshold {}), \
-						skipping for arkoor payment",
-						input.id(), input.expiry_height(), threshold,
-					);
-					continue;
-				}
-			}
-
-			total_amount += input.amount();
-			result.push(input.vtxo);
-
-			if total_amount >= amount {
-				return Ok(result)
-			}
-		}
-
-		bail!("Insufficient money available. Needed {} but {} is available",
-			amount, total_amount,
-		);
+		select_to_cover(self.spendable_vtxos()?, amount, expiry_threshold)
 	}
 
 	/// Fetches all pending lightning receives ordered from newest to oldest.
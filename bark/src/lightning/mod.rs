@@ -3,11 +3,13 @@ pub mod receive;
 
 use anyhow::Context;
 use bitcoin::Amount;
+use bitcoin::secp256k1::Keypair;
 use lightning_invoice::Bolt11Invoice;
 use lnurllib::LnUrlResponse;
 use lnurllib::lightning_address::LightningAddress;
 use log::info;
 
+use ark::{musig, Vtxo, VtxoId};
 use ark::lightning::{Bolt11InvoiceExt, Offer};
 use bitcoin_ext::AmountExt;
 
@@ -106,6 +108,34 @@ async fn lnaddr_invoice(
 	Ok(lnurlp_invoice(&lnurl, amount, comment).await?)
 }
 
+/// Fetches the keypair of each input [Vtxo] using `get_key` and generates a
+/// fresh musig nonce pair for it.
+///
+/// Returns the secret nonces, public nonces, keypairs and ids of the
+/// inputs, all in the same order as the inputs.
+pub(crate) fn prepare_musig_inputs<'a>(
+	inputs: impl IntoIterator<Item = &'a Vtxo>,
+	mut get_key: impl FnMut(&Vtxo) -> anyhow::Result<Keypair>,
+) -> anyhow::Result<(
+	Vec<musig::SecretNonce>, Vec<musig::PublicNonce>, Vec<Keypair>, Vec<VtxoId>,
+)> {
+	let inputs = inputs.into_iter();
+	let (nb_inputs, _) = inputs.size_hint();
+	let mut secs = Vec::with_capacity(nb_inputs);
+	let mut pubs = Vec::with_capacity(nb_inputs);
+	let mut keypairs = Vec::with_capacity(nb_inputs);
+	let mut ids = Vec::with_capacity(nb_inputs);
+	for input in inputs {
+		let keypair = get_key(input)?;
+		let (s, p) = musig::nonce_pair(&keypair);
+		secs.push(s);
+		pubs.push(p);
+		keypairs.push(keypair);
+		ids.push(input.id());
+	}
+	Ok((secs, pubs, keypairs, ids))
+}
+
 
 #[cfg(test)]
 mod test {
@@ -113,10 +143,13 @@ mod test {
 	use std::sync::Arc;
 
 	use bitcoin::Network;
+	use bitcoin::secp256k1::Message;
 	use ark::lightning::{Bolt12Invoice, Bolt12InvoiceExt, Invoice};
+	use ark::test::dummy::{alice_keypair, bob_keypair, dummy_vtxo_for_user};
 	use lightning_invoice::Bolt11Invoice;
 
-	use crate::{Config, SqliteClient, Wallet};
+	use crate::{Config, SqliteClient, Wallet, SECP};
+	use super::*;
 
 	#[allow(unused)] // just exists for compile check
 	async fn pay_lightning_invoice_argument() {
@@ -142,4 +175,32 @@ mod test {
 		let invoice = Invoice::Bolt11("".parse().unwrap());
 		w.pay_lightning_invoice(invoice, None).await.unwrap();
 	}
+
+	#[test]
+	fn prepare_musig_inputs_follows_input_order() {
+		let user_keypairs = [alice_keypair(), bob_keypair(), alice_keypair()];
+		let vtxos = user_keypairs.iter().enumerate().map(|(i, k)| {
+			dummy_vtxo_for_user(*k, Amount::from_sat(10_000 * (i as u64 + 1))).1
+		}).collect::<Vec<_>>();
+		let get_key = |vtxo: &Vtxo| user_keypairs.iter()
+			.find(|k| k.public_key() == vtxo.user_pubkey()).copied()
+			.context("VTXO key not found");
+
+		let (secs, pubs, keypairs, ids) = prepare_musig_inputs(&vtxos, get_key).unwrap();
+		assert_eq!(keypairs, user_keypairs);
+		assert_eq!(ids, vtxos.iter().map(|v| v.id()).collect::<Vec<_>>());
+
+		// each nonce pair belongs to its key if it produces a valid single-signer signature
+		let msg = [0x42; 32];
+		for ((sec, pub_nonce), keypair) in secs.into_iter().zip(&pubs).zip(&keypairs) {
+			let (_, sig) = musig::partial_sign(
+				[keypair.public_key()], musig::nonce_agg(&[pub_nonce]), keypair, sec, msg, None, Some(&[]),
+			);
+			let agg_pk = musig::combine_keys([keypair.public_key()]);
+			SECP.verify_schnorr(&sig.unwrap(), &Message::from_digest(msg), &agg_pk)
+				.expect("nonce pair does not belong to key");
+		}
+
+		assert!(prepare_musig_inputs(&vtxos, |_| Err(anyhow!("VTXO key not found"))).is_err());
+	}
 }
@@ -10,11 +10,11 @@ use server_rpc::protos::{self, lightning_payment_status::PaymentStatus};
 
 use ark::arkoor::ArkoorPackageBuilder;
 use ark::lightning::{Bolt12Invoice, Bolt12InvoiceExt, Invoice, Offer, PaymentHash, Preimage};
-use ark::{ProtocolEncoding, VtxoPolicy, VtxoRequest, musig};
+use ark::{ProtocolEncoding, VtxoPolicy, VtxoRequest};
 use bitcoin_ext::P2TR_DUST;
 
 use crate::Wallet;
-use crate::lightning::lnaddr_invoice;
+use crate::lightning::{lnaddr_invoice, prepare_musig_inputs};
 use crate::movement::{MovementDestination, MovementStatus};
 use crate::movement::update::MovementUpdate;
 use crate::payment_method::PaymentMethod;
@@ -50,16 +50,7 @@ impl Wallet {
 
 		info!("Processing {} HTLC VTXOs for revocation", htlc_vtxos.len());
 
-		let mut secs = Vec::with_capacity(htlc_vtxos.len());
-		let mut pubs = Vec::with_capacity(htlc_vtxos.len());
-		let mut keypairs = Vec::with_capacity(htlc_vtxos.len());
-		for input in htlc_vtxos.iter() {
-			let keypair = self.get_vtxo_key(&input)?;
-			let (s, p) = musig::nonce_pair(&keypair);
-			secs.push(s);
-			pubs.push(p);
-			keypairs.push(keypair);
-		}
+		let (secs, pubs, keypairs, _) = prepare_musig_inputs(&htlc_vtxos, |v| self.get_vtxo_key(v))?;
 
 		let revocation = ArkoorPackageBuilder::new_htlc_revocation(&htlc_vtxos, &pubs)?;
 
@@ -251,7 +242,7 @@ impl Wallet {
 				let min_expiry = payment.htlc_vtxos.iter()
 					.map(|v| v.vtxo.spec().expiry_height).min().unwrap();
 
-				if tip > min_expiry.saturating_sub(self.config().vtxo_refresh_expiry_threshold) {
+				if self.config().htlc_send_needs_exit(tip, min_expiry) {
 					warn!("Some HTLC VTXOs for payment {} are about to expire soon, marking to exit", payment_hash);
 
 					let vtxos = payment.htlc_vtxos
@@ -410,18 +401,7 @@ impl Wallet {
 		let inputs = self.select_vtxos_to_cover(amount, None)
 			.context("Could not find enough suitable VTXOs to cover lightning payment")?;
 
-		let mut secs = Vec::with_capacity(inputs.len());
-		let mut pubs = Vec::with_capacity(inputs.len());
-		let mut keypairs = Vec::with_capacity(inputs.len());
-		let mut input_ids = Vec::with_capacity(inputs.len());
-		for input in inputs.iter() {
-			let keypair = self.get_vtxo_key(&input)?;
-			let (s, p) = musig::nonce_pair(&keypair);
-			secs.push(s);
-			pubs.push(p);
-			keypairs.push(keypair);
-			input_ids.push(input.id());
-		}
+		let (secs, pubs, keypairs, input_ids) = prepare_musig_inputs(&inputs, |v| self.get_vtxo_key(v))?;
 
 		let req = protos::LightningPayHtlcCosignRequest {
 			invoice: invoice.to_string(),
@@ -458,12 +438,15 @@ impl Wallet {
 
 		let (htlc_vtxos, change_vtxo) = builder.build_vtxos(&cosign_resp, &keypairs, secs)?;
 
-		// Validate the new vtxos. They have the same chain anchor.
+		// Validate the new vtxos. They can have different chain anchors.
 		let mut effective_balance = Amount::ZERO;
 		for vtxo in &htlc_vtxos {
 			self.validate_vtxo(vtxo).await?;
 			effective_balance += vtxo.amount();
 		}
+		if let Some(ref change) = change_vtxo {
+			self.validate_vtxo(change).await.context("invalid lightning change vtxo")?;
+		}
 
 		let movement_id = self.movements.new_movement_with_update(
 			self.subsystem_ids[&BarkSubsystem::LightningSend],
@@ -477,14 +460,7 @@ impl Wallet {
 		self.store_locked_vtxos(&htlc_vtxos, Some(movement_id))?;
 		self.mark_vtxos_as_spent(&input_ids)?;
 
-		// Validate the change vtxo. It has the same chain anchor as the last input.
 		if let Some(ref change) = change_vtxo {
-			let last_input = inputs.last().context("no inputs provided")?;
-			let tx = self.chain.get_tx(&last_input.chain_anchor().txid).await?;
-			let tx = tx.with_context(|| {
-				format!("input vtxo chain anchor not found for lightning change vtxo: {}", last_input.chain_anchor().txid)
-			})?;
-			change.validate(&tx).context("invalid lightning change vtxo")?;
 			self.store_spendable_vtxos([change])?;
 		}
 
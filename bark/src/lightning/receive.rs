@@ -8,7 +8,7 @@ use lightning_invoice::Bolt11Invoice;
 use log::{trace, debug, info, warn};
 
 use ark::arkoor::ArkoorPackageBuilder;
-use ark::{ProtocolEncoding, Vtxo, VtxoPolicy, VtxoRequest, musig};
+use ark::{ProtocolEncoding, Vtxo, VtxoPolicy, VtxoRequest};
 use ark::challenges::{LightningReceiveChallenge};
 use ark::lightning::{PaymentHash, Preimage};
 use bitcoin_ext::{AmountExt, BlockDelta, BlockHeight};
@@ -17,6 +17,7 @@ use server_rpc::protos::prepare_lightning_receive_claim_request::LightningReceiv
 
 use crate::subsystem::{BarkSubsystem, LightningMovement, LightningReceiveMovement};
 use crate::{Wallet, error};
+use crate::lightning::prepare_musig_inputs;
 use crate::movement::{MovementDestination, MovementStatus};
 use crate::movement::update::MovementUpdate;
 use crate::persist::models::LightningReceive;
@@ -123,11 +124,9 @@ impl Wallet {
 			ret
 		};
 
-		let (keypairs, sec_nonces, pub_nonces) = inputs.iter().map(|v| {
-			let keypair = self.get_vtxo_key(v)?;
-			let (sec_nonce, pub_nonce) = musig::nonce_pair(&keypair);
-			Ok((keypair, sec_nonce, pub_nonce))
-		}).collect::<anyhow::Result<(Vec<_>, Vec<_>, Vec<_>)>>()?;
+		let (sec_nonces, pub_nonces, keypairs, _) = prepare_musig_inputs(
+			inputs.iter().copied(), |v| self.get_vtxo_key(v),
+		)?;
 
 		// Claiming arkoor against preimage
 		let (claim_keypair, _) = self.derive_store_next_keypair()?;
@@ -60,10 +60,10 @@
 use std::{borrow::Borrow, collections::HashSet};
 
 use anyhow::Context;
-use bitcoin::FeeRate;
+use bitcoin::{Amount, FeeRate};
 use bitcoin_ext::BlockHeight;
 
-use ark::VtxoId;
+use ark::{Vtxo, VtxoId};
 use log::warn;
 
 use crate::Wallet;
@@ -377,3 +377,75 @@ impl FilterVtxos for VtxoStateKind {
 	    Ok(vtxo.state.kind() == *self)
 	}
 }
+
+/// Select VTXOs from `vtxos` to cover `amount`.
+///
+/// VTXOs expiring before `expiry_threshold` are skipped. Returns an error reporting
+/// the requested and available amounts and the shortfall if the amount cannot be reached.
+pub(crate) fn select_to_cover(
+	vtxos: Vec<WalletVtxo>,
+	amount: Amount,
+	expiry_threshold: Option<BlockHeight>,
+) -> anyhow::Result<Vec<Vtxo>> {
+	// Iterate over all rows until the required amount is reached
+	let mut result = Vec::new();
+	let mut total_amount = Amount::ZERO;
+	for input in vtxos {
+		// Check if vtxo is soon-to-expire for arkoor payments
+		if let Some(threshold) = expiry_threshold {
+			if input.expiry_height() < threshold {
+				warn!("VTXO {} is expiring soon (expires at {}, threshold {}), \
+					skipping for arkoor payment",
+					input.id(), input.expiry_height(), threshold,
+				);
+				continue;
+			}
+		}
+
+		total_amount += input.amount();
+		result.push(input.vtxo);
+
+		if total_amount >= amount {
+			return Ok(result)
+		}
+	}
+
+	let shortfall = amount - total_amount;
+	bail!("Insufficient money available. Needed {} but {} is available, short by {}",
+		amount, total_amount, shortfall,
+	);
+}
+
+#[cfg(test)]
+mod test {
+	use ark::test::dummy::dummy_vtxo_for_amount;
+
+	use crate::vtxo::state::VtxoState;
+	use super::*;
+
+	fn wallet_vtxos(amounts: &[u64]) -> Vec<WalletVtxo> {
+		amounts.iter().map(|sat| WalletVtxo {
+			vtxo: dummy_vtxo_for_amount(Amount::from_sat(*sat)).1,
+			state: VtxoState::Spendable,
+		}).collect()
+	}
+
+	#[test]
+	fn select_to_cover_exact_amount() {
+		let vtxos = wallet_vtxos(&[5_000, 1_000, 2_000]);
+		let selected = select_to_cover(vtxos.clone(), Amount::from_sat(6_000), None).unwrap();
+		assert_eq!(selected, vtxos[..2].iter().map(|v| v.vtxo.clone()).collect::<Vec<_>>());
+
+		let selected = select_to_cover(vtxos, Amount::from_sat(8_000), None).unwrap();
+		assert_eq!(selected.len(), 3);
+	}
+
+	#[test]
+	fn select_to_cover_reports_shortfall() {
+		let err = select_to_cover(wallet_vtxos(&[5_000, 1_000]), Amount::from_sat(8_000), None)
+			.unwrap_err().to_string();
+		assert!(err.contains(&Amount::from_sat(8_000).to_string()), "{}", err);
+		assert!(err.contains(&Amount::from_sat(6_000).to_string()), "{}", err);
+		assert!(err.contains(&Amount::from_sat(2_000).to_string()), "{}", err);
+	}
+}
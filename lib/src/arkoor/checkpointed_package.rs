@@ -212,25 +212,11 @@ impl<S: state::BuilderState> CheckpointedPackageBuilder<S> {
 #[cfg(test)]
 mod test {
 	use std::collections::HashMap;
-	use std::str::FromStr;
 
 	use bitcoin::{Transaction, Txid};
 	use bitcoin::secp256k1::Keypair;
 	use super::*;
-	use crate::test::dummy::DummyTestVtxoSpec;
-
-	fn server_keypair() -> Keypair {
-		Keypair::from_str("f7a2a5d150afb575e98fff9caeebf6fbebbaeacfdfa7433307b208b39f1155f2").expect("Invalid key")
-	}
-
-	fn alice_keypair() -> Keypair {
-		Keypair::from_str("9b4382c8985f12e4bd8d1b51e63615bf0187843630829f4c5e9c45ef2cf994a4").expect("Invalid key")
-	}
-
-	fn bob_keypair() -> Keypair {
-		Keypair::from_str("c86435ba7e30d7afd7c5df9f3263ce2eb86b3ff9866a16ccd22a0260496ddf0f").expect("Invalid key")
-	}
-
+	use crate::test::dummy::{alice_keypair, bob_keypair, dummy_vtxo_for_amount, server_keypair};
 
 	fn alice_public_key() -> PublicKey {
 		alice_keypair().public_key()
@@ -240,16 +226,6 @@ mod test {
 		bob_keypair().public_key()
 	}
 
-	fn dummy_vtxo_for_amount(amount: Amount) -> (Transaction, Vtxo) {
-		DummyTestVtxoSpec {
-			amount: amount,
-			expiry_height: 1000,
-			exit_delta: 128,
-			user_keypair: alice_keypair(),
-			server_keypair: server_keypair()
-		}.build()
-	}
-
 	fn verify_package_builder(builder: CheckpointedPackageBuilder<state::Initial>, keypairs: &[Keypair], funding_tx_map: HashMap<Txid, Transaction>) {
 		let user_builder = builder.generate_user_nonces(keypairs).expect("Valid nb of keypairs");
 		let cosign_requests = user_builder.cosign_requests();
@@ -204,20 +204,114 @@ impl<'a> ArkoorPackageBuilder<'a, VtxoRequest> {
 		cosign
 	}
 
+	/// Validate the server's partial signatures.
+	///
+	/// Every input must have exactly one response, and each response must
+	/// sign the arkoor tx we built for that input. Because these txs commit
+	/// to our output policies, a response bound to any other key is rejected.
 	pub fn verify_cosign_response<T: Borrow<ArkoorCosignResponse>>(
 		&self,
 		server_cosign: &[T],
 	) -> bool {
-		for (idx, builder) in self.arkoors.iter().enumerate() {
-			if let Some(cosign) = server_cosign.get(idx) {
-				if !builder.verify_cosign_response(cosign.borrow()) {
-					return false;
-				}
-			} else {
-				return false;
-			}
+		if server_cosign.len() != self.arkoors.len() {
+			return false;
 		}
-		true
+
+		self.arkoors.iter().zip(server_cosign)
+			.all(|(builder, cosign)| builder.verify_cosign_response(cosign.borrow()))
 	}
 }
 
+#[cfg(test)]
+mod test {
+	use crate::lightning::PaymentHash;
+	use crate::test::dummy::{alice_keypair, bob_keypair, dummy_vtxo_for_amount, server_keypair};
+	use super::*;
+
+	fn htlc_request(user_pubkey: PublicKey) -> VtxoRequest {
+		VtxoRequest {
+			amount: Amount::from_sat(12_000),
+			policy: VtxoPolicy::new_server_htlc_send(user_pubkey, PaymentHash::from([0xab; 32]), 900),
+		}
+	}
+
+	#[test]
+	fn reject_cosign_response_bound_to_other_key() {
+		// Alice pays an HTLC of 12_000 sats using two inputs, so the package
+		// contains two arkoors and expects two cosign responses
+		let inputs = [
+			dummy_vtxo_for_amount(Amount::from_sat(10_000)).1,
+			dummy_vtxo_for_amount(Amount::from_sat(5_000)).1,
+		];
+		let nonces = inputs.iter()
+			.map(|_| musig::nonce_pair(&alice_keypair()).1)
+			.collect::<Vec<_>>();
+		let change_pk = Some(alice_keypair().public_key());
+
+		let user = ArkoorPackageBuilder::new(
+			&inputs, &nonces, htlc_request(alice_keypair().public_key()), change_pk,
+		).expect("valid package");
+		assert_eq!(user.arkoors.len(), 2);
+
+		let cosign = ArkoorPackageBuilder::new(
+			&inputs, &nonces, htlc_request(alice_keypair().public_key()), change_pk,
+		).expect("valid package").server_cosign(&server_keypair());
+		assert!(user.verify_cosign_response(&cosign));
+
+		// The server cosigned a package with the HTLCs bound to Bob's key
+		let tampered = ArkoorPackageBuilder::new(
+			&inputs, &nonces, htlc_request(bob_keypair().public_key()), change_pk,
+		).expect("valid package").server_cosign(&server_keypair());
+		assert!(!user.verify_cosign_response(&tampered));
+
+		// Only one of the responses is bound to Bob's key
+		let mixed = vec![&cosign[0], &tampered[1]];
+		assert!(!user.verify_cosign_response(&mixed));
+		let mixed = vec![&tampered[0], &cosign[1]];
+		assert!(!user.verify_cosign_response(&mixed));
+
+		// Valid responses followed by superfluous ones
+		let extra = cosign.iter().chain(tampered.iter()).collect::<Vec<_>>();
+		assert!(!user.verify_cosign_response(&extra));
+
+		// Missing a response
+		assert!(!user.verify_cosign_response(&cosign[..1]));
+	}
+
+	#[test]
+	fn change_keeps_chain_anchor_of_last_input() {
+		// Alice's inputs come from two different chain anchors
+		let (funding_tx_1, vtxo_1) = dummy_vtxo_for_amount(Amount::from_sat(10_000));
+		let (funding_tx_2, vtxo_2) = dummy_vtxo_for_amount(Amount::from_sat(5_000));
+		assert_ne!(vtxo_1.chain_anchor().txid, vtxo_2.chain_anchor().txid);
+		let inputs = [vtxo_1, vtxo_2];
+
+		let (sec_nonces, pub_nonces): (Vec<_>, Vec<_>) = inputs.iter()
+			.map(|_| musig::nonce_pair(&alice_keypair()))
+			.unzip();
+		let keypairs = [alice_keypair(), alice_keypair()];
+
+		let builder = ArkoorPackageBuilder::new(
+			&inputs,
+			&pub_nonces,
+			htlc_request(alice_keypair().public_key()),
+			Some(alice_keypair().public_key()),
+		).expect("valid package");
+		let cosign = builder.server_cosign(&server_keypair());
+		assert!(builder.verify_cosign_response(&cosign));
+
+		let (htlcs, change) = builder.build_vtxos(&cosign, &keypairs, sec_nonces)
+			.expect("valid cosign response");
+		assert_eq!(htlcs.len(), 2);
+		htlcs[0].validate(&funding_tx_1).expect("valid first htlc vtxo");
+		htlcs[1].validate(&funding_tx_2).expect("valid second htlc vtxo");
+
+		// The change is created from the last input, so it only validates
+		// against that input's chain anchor
+		let change = change.expect("change vtxo");
+		assert_eq!(change.amount(), Amount::from_sat(3_000));
+		assert_eq!(change.chain_anchor().txid, funding_tx_2.compute_txid());
+		change.validate(&funding_tx_2).expect("valid change vtxo");
+		assert!(change.validate(&funding_tx_1).is_err());
+	}
+}
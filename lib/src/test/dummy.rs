@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use bitcoin::OutPoint;
 
 use bitcoin::{Amount, TxIn, TxOut, Transaction, Witness, ScriptBuf, Sequence};
@@ -80,6 +82,39 @@ impl DummyTestVtxoSpec {
 	}
 }
 
+/// The server keypair used by the dummy test fixtures.
+pub fn server_keypair() -> Keypair {
+	Keypair::from_str("f7a2a5d150afb575e98fff9caeebf6fbebbaeacfdfa7433307b208b39f1155f2").expect("Invalid key")
+}
+
+/// The keypair of alice, the default user in the dummy test fixtures.
+pub fn alice_keypair() -> Keypair {
+	Keypair::from_str("9b4382c8985f12e4bd8d1b51e63615bf0187843630829f4c5e9c45ef2cf994a4").expect("Invalid key")
+}
+
+/// The keypair of bob, a second user in the dummy test fixtures.
+pub fn bob_keypair() -> Keypair {
+	Keypair::from_str("c86435ba7e30d7afd7c5df9f3263ce2eb86b3ff9866a16ccd22a0260496ddf0f").expect("Invalid key")
+}
+
+/// Build a board VTXO of `amount` owned by [alice_keypair] and cosigned by
+/// [server_keypair], together with its funding tx.
+pub fn dummy_vtxo_for_amount(amount: Amount) -> (Transaction, Vtxo) {
+	dummy_vtxo_for_user(alice_keypair(), amount)
+}
+
+/// Build a board VTXO of `amount` owned by `user_keypair` and cosigned by
+/// [server_keypair], together with its funding tx.
+pub fn dummy_vtxo_for_user(user_keypair: Keypair, amount: Amount) -> (Transaction, Vtxo) {
+	DummyTestVtxoSpec {
+		amount: amount,
+		expiry_height: 1000,
+		exit_delta: 128,
+		user_keypair: user_keypair,
+		server_keypair: server_keypair(),
+	}.build()
+}
+
 #[test]
 fn create_dummy_output() {
 
@@ -1444,4 +1444,34 @@ pub mod test {
 		assert_eq!(vtxos.arkoor2_vtxo.exit_depth(), 1 /* cosign */ + 2 /* arkoor */);
 		assert_eq!(vtxos.arkoor3_vtxo.exit_depth(), 3 /* cosign */ + 1 /* arkoor */);
 	}
+
+	#[test]
+	fn server_htlc_send_policy_encoding() {
+		let user_pubkey = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+			.parse::<PublicKey>().unwrap();
+		// asymmetric so that reversed hash bytes don't go unnoticed
+		let payment_hash = PaymentHash::from(std::array::from_fn::<u8, 32, _>(|i| i as u8));
+		let htlc_expiry = 800_000;
+
+		let policy = VtxoPolicy::new_server_htlc_send(user_pubkey, payment_hash, htlc_expiry);
+		assert_eq!(policy.policy_type(), VtxoPolicyKind::ServerHtlcSend);
+
+		// pin the wire format so changes to the policy encoding don't go unnoticed
+		assert_eq!(
+			policy.serialize().as_hex().to_string(),
+			concat!(
+				"01",
+				"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+				"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+				"00350c00",
+			),
+		);
+		encoding_roundtrip(&policy);
+
+		let decoded = VtxoPolicy::deserialize(&policy.serialize()).unwrap();
+		let htlc = decoded.as_server_htlc_send().expect("server htlc send policy");
+		assert_eq!(htlc.user_pubkey, user_pubkey);
+		assert_eq!(htlc.payment_hash, payment_hash);
+		assert_eq!(htlc.htlc_expiry, htlc_expiry);
+	}
 }
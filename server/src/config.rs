@@ -15,6 +15,9 @@ use cln_rpc::node_client::NodeClient;
 use crate::{forfeits, serde_util, sweeps, vtxopool};
 use crate::secret::Secret;
 
+/// The BOLT 11 default `min_final_cltv_expiry_delta`, the minimum number of
+/// blocks even a direct lightning payment needs.
+const MIN_FINAL_CLTV_EXPIRY_DELTA: BlockDelta = 18;
 
 /// Wraps another config struct but adds an enabled boolean
 pub enum OptionalService<T> {
@@ -353,6 +356,9 @@ pub struct Config {
 	///
 	/// Note: it is added to [Config::htlc_expiry_delta] to provide `maxdelay` in
 	/// xpay call.
+	///
+	/// Must leave room for at least `min_final_cltv_expiry_delta` (18) blocks
+	/// of `maxdelay` on top of [Config::htlc_expiry_delta].
 	pub htlc_send_expiry_delta: BlockDelta,
 	/// Maximum CLTV delta server will allow clients to request an
 	/// invoice generation with.
@@ -434,6 +440,20 @@ impl Config {
 	/// It also checks if all required configurations are available
 	pub fn validate(&self) -> anyhow::Result<()> {
 		self.bitcoind.validate()?;
+
+		// The lightning payment has to complete before the HTLC-send VTXO
+		// expires, while keeping `htlc_expiry_delta` blocks of margin. What
+		// is left is the `maxdelay` of the payment, which must at least fit
+		// the final hop.
+		let min_send_delta = self.htlc_expiry_delta.saturating_add(MIN_FINAL_CLTV_EXPIRY_DELTA);
+		if self.htlc_send_expiry_delta < min_send_delta {
+			bail!("Invalid configuration htlc_send_expiry_delta ({}). It must be at least \
+				htlc_expiry_delta ({}) + {} blocks, otherwise HTLC-send VTXOs expire too soon \
+				to perform a lightning payment.",
+				self.htlc_send_expiry_delta, self.htlc_expiry_delta, MIN_FINAL_CLTV_EXPIRY_DELTA,
+			);
+		}
+
 		Ok(())
 	}
 
@@ -598,6 +618,28 @@ mod test {
 		cfg.validate().expect_err("Invalid. Either cookie or pass but not both");
 	}
 
+	#[test]
+	fn validate_htlc_send_expiry_delta() {
+		let mut cfg = Config::load(DEFAULT_CAPTAIND_CONFIG_PATH).unwrap();
+		cfg.bitcoind.cookie = Some(".cookie".into());
+		cfg.validate().expect("default config should be valid");
+
+		cfg.htlc_send_expiry_delta = 0;
+		cfg.validate().expect_err("Invalid because htlc_send_expiry_delta is zero");
+
+		cfg.htlc_send_expiry_delta = cfg.htlc_expiry_delta;
+		cfg.validate().expect_err("Invalid because there is no room for the payment");
+
+		cfg.htlc_send_expiry_delta = cfg.htlc_expiry_delta + 1;
+		cfg.validate().expect_err("Invalid because the payment can't fit the final hop");
+
+		cfg.htlc_send_expiry_delta = cfg.htlc_expiry_delta + MIN_FINAL_CLTV_EXPIRY_DELTA - 1;
+		cfg.validate().expect_err("Invalid because the payment can't fit the final hop");
+
+		cfg.htlc_send_expiry_delta = cfg.htlc_expiry_delta + MIN_FINAL_CLTV_EXPIRY_DELTA;
+		cfg.validate().expect("This config should be valid");
+	}
+
 	#[test]
 	fn init_accepts_full_cln_config() {
 		let bitcoind_cookie = Some(PathBuf::from("/not/hot/dog/but/cookie"));
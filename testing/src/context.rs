@@ -382,6 +382,7 @@ impl TestContext {
 			vtxo_refresh_expiry_threshold: 24,
 			vtxo_exit_margin: 12,
 			htlc_recv_claim_delta: 18,
+			htlc_send_exit_expiry_threshold: None,
 			fallback_fee_rate: Some(FeeRate::from_sat_per_vb_unchecked(5)),
 			round_tx_required_confirmations: constants::ROUND_CONFIRMATIONS,
 		}